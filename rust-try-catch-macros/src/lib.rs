@@ -31,6 +31,19 @@ pub fn closure_throw_guard(item: TokenStream1) -> TokenStream1 {
     wrap_closure(parse_macro_input!(item as ExprClosure)).into()
 }
 
+
+#[proc_macro_attribute]
+pub fn result_guard(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
+    if !attr.is_empty() {
+        let message = format!("Unexpected attributes \"{attr}\"");
+        return syn::Error::new(TokenStream::from(attr).span(), message)
+            .into_compile_error()
+            .into()
+    }
+
+    wrap_func_result_guard(parse_macro_input!(item as ItemFn)).into()
+}
+
 fn throw_guard_body(async_ness: Option<&syn::Token![async]>, fn_body: TokenStream) -> TokenStream {
     let span = fn_body.span();
     match &async_ness {
@@ -63,6 +76,61 @@ fn wrap_closure(closure: ExprClosure) -> TokenStream {
     closure.into_token_stream()
 }
 
+fn result_guard_body(async_ness: Option<&syn::Token![async]>, fn_body: TokenStream) -> TokenStream {
+    let span = fn_body.span();
+    match &async_ness {
+        Some(_) => quote_spanned! { span=>
+            {
+                let mut fut = ::core::pin::pin!(async { #fn_body });
+                ::core::future::poll_fn(move |cx| {
+                    match ::rust_try_catch::catch_boundary(|| {
+                        ::core::future::Future::poll(::core::pin::Pin::as_mut(&mut fut), cx)
+                    }) {
+                        ::core::result::Result::Ok(::core::task::Poll::Ready(val)) =>
+                            ::core::task::Poll::Ready(::core::result::Result::Ok(val)),
+                        ::core::result::Result::Ok(::core::task::Poll::Pending) =>
+                            ::core::task::Poll::Pending,
+                        ::core::result::Result::Err(caught) =>
+                            ::core::task::Poll::Ready(::core::result::Result::Err(caught)),
+                    }
+                }).await
+            }
+        },
+        None => quote_spanned! { span=> ::rust_try_catch::catch_boundary(|| #fn_body) },
+    }
+}
+
+fn wrap_func_result_guard(input: ItemFn) -> TokenStream {
+    if let Some(token) = input.sig.constness {
+        return syn::Error::new(token.span, "can't drive try catch logic in const")
+            .into_compile_error()
+    }
+
+    if let Some(variadic) = input.sig.variadic {
+        return syn::Error::new(variadic.span(), "using variadic arguments would cause UB!!!")
+            .into_compile_error()
+    }
+
+    let ret_ty: syn::Type = match &input.sig.output {
+        syn::ReturnType::Default => parse_quote!(()),
+        syn::ReturnType::Type(_, ty) => (**ty).clone(),
+    };
+
+    let outer_fn_body = result_guard_body(input.sig.asyncness.as_ref(), input.block.into_token_stream());
+
+    let mut sig = input.sig;
+    sig.output = parse_quote!(-> ::core::result::Result<#ret_ty, ::rust_try_catch::Caught>);
+
+    let new_fn = ItemFn {
+        attrs: input.attrs,
+        vis: input.vis,
+        sig,
+        block: parse_quote!({ #outer_fn_body }),
+    };
+
+    new_fn.into_token_stream()
+}
+
 fn wrap_func(input: ItemFn) -> TokenStream {
     if let Some(token) = input.sig.constness {
         return syn::Error::new(token.span, "can't drive try catch logic in const")
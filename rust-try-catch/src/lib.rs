@@ -34,8 +34,28 @@ compile_error!("Try catch only works when panic = \"unwind\"");
 /// ```
 ///
 /// - **try block**: The primary code to execute.
-/// - **catch blocks**: Handle exceptions matching specific types.
+/// - **catch blocks**: Handle exceptions matching specific types. A block written as
+///   `catch (e => dyn Error)` instead matches any exception thrown via [`throw_dyn`] (or
+///   [`tri_dyn!`]), regardless of its concrete type, binding `e: &dyn std::error::Error` so its
+///   `.source()` chain can be walked, and it is skipped (falling through to the next block) for
+///   anything not thrown through [`throw_dyn`]/[`tri_dyn!`]. Concrete-type `catch` blocks are
+///   tried first, in the order they're written, like `match` arms; `catch (e => dyn Error)`
+///   blocks are always tried only after every concrete-type block has failed to match,
+///   regardless of where in the arm list they're written. Either form of `catch` block can add a trailing backtrace
+///   binding, e.g. `catch (e => MyErr, bt)` or `catch (e => dyn Error, bt)`, to get the
+///   `&Backtrace` captured at the `throw` site alongside the caught value - call `bt.status()` for
+///   its `BacktraceStatus` (whether it was actually captured; see [`throw`] vs [`throw_force`]). A
+///   concrete-type block can also add an `if` guard, e.g. `catch (e => HttpError if e.status ==
+///   503)`: if the guard evaluates to `false` the value is put back and matching continues with
+///   the next block, instead of running the body.
 /// - **catch exception block** (optional): A generic handler for exceptions not caught by specific `catch` blocks.
+///   Binds `e: `[`Thrown`]` rather than the raw payload, so it can be annotated and re-raised via
+///   [`Thrown::rethrow_with_context`]. There's no separate `, bt` binding here (unlike the
+///   type-matching blocks above) since `e` already exposes the backtrace directly: `&e.backtrace`
+///   is the `&Backtrace` and `e.backtrace.status()` its `BacktraceStatus`. To get back to the
+///   original thrown value, either read the `e.source` field directly (it's still a plain
+///   `Box<dyn Any + Send>`, so `e.source.downcast::<T>()` works exactly as before) or call the
+///   equivalent [`Thrown::downcast`].
 /// - **catch panic block** (optional): Handle non-exception panics.
 /// - **finally block** (optional): Executes unconditionally after the `try` block and any `catch` blocks.
 ///
@@ -85,7 +105,10 @@ compile_error!("Try catch only works when panic = \"unwind\"");
 ///         another_function();
 ///         0
 ///     } catch exception (e) {
-///         println!("Caught an exception: {:?}", e);
+///         // `e` is a `Thrown`, not the raw payload; get the original value back via
+///         // `e.source` (a plain `Box<dyn Any + Send>`) or `Thrown::downcast`.
+///         let payload = e.downcast::<&'static str>().ok();
+///         println!("Caught an exception, payload: {payload:?}");
 ///         -2
 ///     }
 /// };
@@ -107,6 +130,66 @@ compile_error!("Try catch only works when panic = \"unwind\"");
 /// assert_eq!(result, -101);
 /// ```
 ///
+/// ## Matching any thrown `dyn Error`
+/// ```
+/// use std::error::Error;
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct MyError;
+/// impl fmt::Display for MyError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("my error")
+///     }
+/// }
+/// impl Error for MyError {}
+///
+/// let result = rust_try_catch::try_catch! {
+///     try {
+///         rust_try_catch::throw_dyn(MyError);
+///         0
+///     } catch (e => dyn Error) {
+///         println!("Caught a dyn Error: {e}, source: {:?}", e.source());
+///         -1
+///     }
+/// };
+/// assert_eq!(result, -1);
+/// ```
+///
+/// ## Binding the backtrace alongside the caught value
+/// ```
+/// let result = rust_try_catch::try_catch! {
+///     try {
+///         rust_try_catch::throw_force("boom");
+///         0
+///     } catch (e => &'static str, bt) {
+///         println!("Caught {e} at:\n{bt}");
+///         -1
+///     }
+/// };
+/// assert_eq!(result, -1);
+/// ```
+///
+/// ## Guarding a catch block on the caught value
+/// ```
+/// #[derive(Debug)]
+/// struct HttpError { status: u16 }
+///
+/// let result = rust_try_catch::try_catch! {
+///     try {
+///         rust_try_catch::throw(HttpError { status: 503 });
+///         0
+///     } catch (e => HttpError if e.status == 500) {
+///         println!("Internal server error: {e:?}");
+///         -500
+///     } catch (e => HttpError) {
+///         println!("Other HTTP error: {e:?}");
+///         -1
+///     }
+/// };
+/// assert_eq!(result, -1);
+/// ```
+///
 /// ## Using a finally block
 /// ```
 /// let mut cleanup = false;
@@ -198,7 +281,7 @@ macro_rules! try_catch {
     {
         try {
             $($try_body: tt)*
-        } $(catch ($exception_name: pat => $exception_ty:ty) {
+        } $(catch ($exception_name: pat => $($exception_ty: tt)+) {
             $($catch_body: tt)*
         })* $(catch exception ($catch_all_exception_name: pat) {
             $($catch_all_exception_body: tt)*
@@ -249,21 +332,23 @@ macro_rules! try_catch {
                     }
                 };
 
+                // Two passes over the same arms: concrete-type arms are tried in written order
+                // first, then `dyn Error` arms are tried in written order - regardless of how
+                // the two kinds are interleaved in the source. `__catch_arm!` expands to nothing
+                // for arms that don't belong to the pass it's told it's in.
                 $(
-                    match exception.source.downcast::<$exception_ty>() {
-                        Ok(box_error) => {
-                            let $exception_name: $exception_ty = *box_error;
-
-                            break 'ret_from_err ({
-                               $($catch_body)*
-                            })
-                        }
-                        Err(other_error) => exception.source = other_error,
-                    }
+                    $crate::__catch_arm!(concrete; exception; $exception_name; ($($exception_ty)+); {
+                        $($catch_body)*
+                    }; 'ret_from_err);
+                )*
+                $(
+                    $crate::__catch_arm!(dyn_only; exception; $exception_name; ($($exception_ty)+); {
+                        $($catch_body)*
+                    }; 'ret_from_err);
                 )*
 
                 $({
-                    let $catch_all_exception_name = exception.source;
+                    let $catch_all_exception_name = *exception;
                     break 'ret_from_err ({$($catch_all_exception_body)*})
                 })?
 
@@ -291,22 +376,192 @@ macro_rules! tri {
     };
 }
 
+/// Like [`tri!`], but for a `Result` whose error is kept reachable as a `dyn Error` (see
+/// [`throw_dyn`]), so a `catch (e => dyn Error)` arm downstream can match it without knowing
+/// its concrete type.
+#[macro_export]
+macro_rules! tri_dyn {
+    ($expr: expr) => {
+        match ($expr) {
+            ::core::result::Result::Ok(val) => val,
+            ::core::result::Result::Err(err) => $crate::throw_dyn(err),
+        }
+    };
+}
+
+/// Throws `$err` if `$cond` is `false`, otherwise evaluates to `()`.
+///
+/// Shorthand for the `if !cond { throw(err) }` pattern, mirroring anyhow's `ensure!`. `$err` goes
+/// through the same `throw` used by [`tri!`], so it's catchable by any matching `catch` arm.
+///
+/// ```
+/// let result = rust_try_catch::try_catch! {
+///     try {
+///         rust_try_catch::ensure!(1 + 1 == 3, "math is broken");
+///         0
+///     } catch (e => &'static str) {
+///         println!("Caught: {e}");
+///         -1
+///     }
+/// };
+/// assert_eq!(result, -1);
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond: expr, $err: expr) => {
+        if !($cond) {
+            $crate::throw($err);
+        }
+    };
+}
+
+/// Unconditionally throws `$err`. Shorthand for `$crate::throw($err)`, mirroring anyhow's
+/// `bail!`, for use where an early, unconditional exit via `throw` reads more clearly than a
+/// bare call.
+///
+/// ```
+/// let result = rust_try_catch::try_catch! {
+///     try {
+///         rust_try_catch::bail!("always fails");
+///         #[allow(unreachable_code)]
+///         0
+///     } catch (e => &'static str) {
+///         println!("Caught: {e}");
+///         -1
+///     }
+/// };
+/// assert_eq!(result, -1);
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($err: expr) => {
+        $crate::throw($err)
+    };
+}
+
 #[doc(hidden)]
 pub struct Thrown {
     pub source: Box<dyn Any + Send>,
     pub type_name: &'static str,
-    pub backtrace: Backtrace
+    pub backtrace: Backtrace,
+    /// Contextual messages attached while this exception unwound, innermost (closest to the
+    /// `throw` site) first. See [`Thrown::rethrow_with_context`] and [`Thrown::chain`].
+    pub context: Vec<String>,
+    /// Set by [`throw_dyn`] to a function that reinterprets `source` as a `dyn Error`, so a
+    /// `catch (e => dyn Error)` arm can obtain one without knowing the concrete type.
+    pub error_view: Option<fn(&(dyn Any + Send)) -> &(dyn std::error::Error + 'static)>,
+}
+
+impl std::fmt::Debug for Thrown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Thrown")
+            .field("type_name", &self.type_name)
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Thrown {
+    /// Pushes a contextual message onto this exception and resumes unwinding it.
+    ///
+    /// Intended for use inside a `catch exception` block: annotate where an exception was
+    /// observed on its way through a nested `try_catch!` before letting it keep propagating,
+    /// mirroring `anyhow`'s `.context(..)` chaining.
+    ///
+    /// ```
+    /// # use rust_try_catch::{try_catch, throw};
+    /// let result = try_catch! {
+    ///     try {
+    ///         try_catch! {
+    ///             try {
+    ///                 throw("boom");
+    ///             } catch exception (e) {
+    ///                 e.rethrow_with_context("while handling the inner request")
+    ///             }
+    ///         }
+    ///     } catch exception (e) {
+    ///         e.context.len()
+    ///     }
+    /// };
+    /// assert_eq!(result, 1);
+    /// ```
+    pub fn rethrow_with_context(mut self, msg: impl Into<String>) -> ! {
+        self.context.push(msg.into());
+        std::panic::resume_unwind(Box::new(self))
+    }
+
+    /// Iterates over this exception's contextual messages, from the outermost (most recently
+    /// attached) down to the one closest to the original `throw` site.
+    pub fn chain(&self) -> impl Iterator<Item = &str> {
+        self.context.iter().rev().map(String::as_str)
+    }
+
+    /// Returns this exception's payload as a `&dyn Error`, if it was thrown via [`throw_dyn`].
+    ///
+    /// This is what powers the `catch (e => dyn Error)` arm of [`try_catch!`]; values thrown
+    /// through plain [`throw`]/[`tri!`] have no error view and always return `None` here, even
+    /// if their concrete type happens to implement `Error`.
+    pub fn as_dyn_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error_view.map(|view| view(&*self.source))
+    }
+
+    /// Attempts to downcast `self.source` back to its concrete type, giving back `self`
+    /// unchanged on a mismatch so the caller can try another type or re-raise it. Equivalent to
+    /// `self.source.downcast::<E>()`, provided for parity with [`Caught::downcast`] since a
+    /// `catch exception` block binds a `Thrown` rather than the raw `Box<dyn Any + Send>`.
+    pub fn downcast<E: Any>(mut self) -> Result<E, Self> {
+        match self.source.downcast::<E>() {
+            Ok(boxed) => Ok(*boxed),
+            Err(source) => {
+                self.source = source;
+                Err(self)
+            }
+        }
+    }
 }
 
 /// Calling throw always results in a panic
-/// 
+///
 /// for proper usage users must ensure that there is a function annotated with `rust_try_catch::throw_guard`
 /// up in the call chain
+///
+/// The backtrace captured at the `throw` site (reachable via a `catch (e => Ty, bt)` binding, or
+/// [`Caught::backtrace`]) follows [`Backtrace::capture`]'s policy, i.e. it's only actually
+/// captured when `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE` ask for one. Use [`throw_force`] to
+/// always capture one regardless of the environment.
 pub fn throw<T: Any + Send + 'static>(x: T) -> ! {
     std::panic::resume_unwind(Box::new(Thrown {
         source: Box::new(x),
         type_name: std::any::type_name::<T>(),
-        backtrace: Backtrace::force_capture()
+        backtrace: Backtrace::capture(),
+        context: Vec::new(),
+        error_view: None,
+    }))
+}
+
+/// Like [`throw`], but always captures a backtrace (via [`Backtrace::force_capture`]) regardless
+/// of `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`. Prefer [`throw`] on throughput-sensitive paths,
+/// since forcing the capture walks the unwind tables on every call.
+pub fn throw_force<T: Any + Send + 'static>(x: T) -> ! {
+    std::panic::resume_unwind(Box::new(Thrown {
+        source: Box::new(x),
+        type_name: std::any::type_name::<T>(),
+        backtrace: Backtrace::force_capture(),
+        context: Vec::new(),
+        error_view: None,
+    }))
+}
+
+/// Like [`throw`], but keeps `err` reachable as a `dyn Error` (see [`Thrown::as_dyn_error`]) so
+/// a `catch (e => dyn Error)` arm can match it and walk its `.source()` chain without knowing
+/// the concrete error type up front.
+pub fn throw_dyn<E: std::error::Error + Send + Sync + 'static>(err: E) -> ! {
+    std::panic::resume_unwind(Box::new(Thrown {
+        type_name: std::any::type_name::<E>(),
+        backtrace: Backtrace::capture(),
+        context: Vec::new(),
+        error_view: Some(|any| any.downcast_ref::<E>().unwrap() as &(dyn std::error::Error + 'static)),
+        source: Box::new(err),
     }))
 }
 
@@ -319,6 +574,93 @@ pub fn throw<T: Any + Send + 'static>(x: T) -> ! {
 /// throws, the process might exit abruptly due to a panic with an unspecified load
 pub use rust_try_catch_macros::{throw_guard, closure_throw_guard};
 
+/// Annotates a function so that, instead of turning an unhandled exception into a panic like
+/// `throw_guard` does, it returns `Result<T, Caught>` - `Ok(T)` on a normal return and
+/// `Err(Caught)` if a `throw`/`throw_dyn` anywhere in the call stack went unhandled. Genuine
+/// panics still propagate as panics.
+///
+/// ```
+/// #[rust_try_catch::result_guard]
+/// fn might_throw(fail: bool) -> i32 {
+///     if fail {
+///         rust_try_catch::throw("nope");
+///     }
+///     42
+/// }
+///
+/// assert!(matches!(might_throw(false), Ok(42)));
+/// assert!(might_throw(true).is_err());
+/// ```
+pub use rust_try_catch_macros::result_guard;
+
+/// An exception intercepted by [`catch_boundary`] (or `#[result_guard]`) instead of being
+/// allowed to turn into a panic.
+pub struct Caught(Thrown);
+
+impl Caught {
+    /// The `type_name` of the value originally passed to `throw`/`throw_dyn`.
+    pub fn type_name(&self) -> &'static str {
+        self.0.type_name
+    }
+
+    /// The backtrace captured at the `throw` site.
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.0.backtrace
+    }
+
+    /// Contextual messages attached via [`Thrown::rethrow_with_context`], from the outermost
+    /// down to the one closest to the original `throw` site.
+    pub fn chain(&self) -> impl Iterator<Item = &str> {
+        self.0.chain()
+    }
+
+    /// This exception's payload as a `dyn Error`, if it was thrown via [`throw_dyn`].
+    pub fn as_dyn_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.as_dyn_error()
+    }
+
+    /// Attempts to downcast the caught payload back to its concrete type, giving back `self`
+    /// unchanged on a mismatch so the caller can try another type or re-raise it.
+    pub fn downcast<E: Any>(self) -> Result<E, Self> {
+        match self.0.source.downcast::<E>() {
+            Ok(boxed) => Ok(*boxed),
+            Err(source) => Err(Caught(Thrown { source, ..self.0 })),
+        }
+    }
+}
+
+impl std::fmt::Debug for Caught {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Caught")
+            .field("type_name", &self.0.type_name)
+            .field("context", &self.0.context)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs `f`, intercepting any exception thrown from within it as a [`Caught`] value instead of
+/// letting it keep unwinding (and eventually turn into a panic, e.g. via `__throw_driver`).
+/// Genuine panics - anything not produced by `throw`/`throw_dyn` - still propagate normally.
+///
+/// This gives a non-panicking `try` boundary usable at any point in the call stack, unlike
+/// [`try_catch!`] which can only handle exceptions it syntactically lists.
+///
+/// ```
+/// let result = rust_try_catch::catch_boundary(|| {
+///     rust_try_catch::throw("boom");
+/// });
+/// assert!(result.is_err());
+/// ```
+pub fn catch_boundary<T>(f: impl FnOnce() -> T) -> Result<T, Caught> {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(val) => Ok(val),
+        Err(panic_payload) => match panic_payload.downcast::<Thrown>() {
+            Ok(thrown) => Err(Caught(*thrown)),
+            Err(normal_panic) => std::panic::resume_unwind(normal_panic),
+        },
+    }
+}
+
 
 #[doc(hidden)]
 #[track_caller]
@@ -327,8 +669,13 @@ pub fn __throw_driver<T>(main: impl FnOnce() -> T) -> T {
     #[inline(never)]
     fn inner(f: &mut dyn FnMut()) {
         if let Err(panic) = std::panic::catch_unwind(AssertUnwindSafe(f)) {
-            if let Some(Thrown { type_name, backtrace, .. }) = panic.downcast_ref() {
-                panic!("unhandled exception {type_name} at {backtrace}");
+            if let Some(thrown @ Thrown { type_name, backtrace, .. }) = panic.downcast_ref::<Thrown>() {
+                if thrown.context.is_empty() {
+                    panic!("unhandled exception {type_name} at {backtrace}");
+                }
+
+                let context = thrown.chain().collect::<Vec<_>>().join("\n  ");
+                panic!("unhandled exception {type_name} at {backtrace}\ncontext:\n  {context}");
             }
 
             std::panic::resume_unwind(panic)
@@ -358,4 +705,110 @@ macro_rules! __count_blocks {
     ({$($tt:tt)*} $($rest:tt)*) => {
         1 + $crate::__count_blocks!($($rest)*)
     }
+}
+
+/// Expands a single `catch ($name => ...) { $body }` arm.
+///
+/// Takes everything in the arm's type position as raw tokens (rather than a `ty` fragment) so
+/// it can tell the literal `dyn Error` apart from an ordinary concrete type, and can find an
+/// optional trailing `if $guard` and/or `, $bt` - once a `ty` fragment has matched, the tokens
+/// behind it are opaque and can no longer be matched against literal tokens like `dyn`/`Error`
+/// or `if`, and `ty` can't even be followed by a literal `if` to begin with.
+///
+/// `$name` is already a fully parsed `pat` by the time [`try_catch!`] forwards it here, but a
+/// `pat` fragment can only be followed by `=>`, `,`, `=`, `|`, `if`, `in` or a closing delimiter
+/// - not the `;` this macro uses to separate arguments. So it's re-captured as `tt` instead; a
+/// forwarded `pat` nonterminal is one opaque token tree either way, and splicing it back out in
+/// pattern position (`let $name: Ty = ...`) reparses it as the same pattern.
+///
+/// Takes a leading `concrete`/`dyn_only` pass marker - [`try_catch!`] expands every arm twice,
+/// once per pass, so that `dyn Error` arms are always tried after every concrete-type arm no
+/// matter where they were written; an arm that doesn't belong to the pass it's invoked with
+/// expands to nothing.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __catch_arm {
+    (concrete; $exception: ident; $name: tt; (dyn Error $($rest: tt)*); { $($body: tt)* }; $label: lifetime) => {};
+    (dyn_only; $exception: ident; $name: tt; (dyn Error $($rest: tt)*); { $($body: tt)* }; $label: lifetime) => {
+        $crate::__catch_dyn_error_arm!($exception; $name; ($($rest)*); { $($body)* }; $label)
+    };
+    (dyn_only; $exception: ident; $name: tt; ($($ty_and_rest: tt)+); { $($body: tt)* }; $label: lifetime) => {};
+    (concrete; $exception: ident; $name: tt; ($($ty_and_rest: tt)+); { $($body: tt)* }; $label: lifetime) => {
+        $crate::__catch_ty_arm!($exception; $name; []; ($($ty_and_rest)+); { $($body)* }; $label)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __catch_dyn_error_arm {
+    ($exception: ident; $name: tt; (, $bt: pat); { $($body: tt)* }; $label: lifetime) => {
+        if let Some(err) = $exception.as_dyn_error() {
+            let $name: &(dyn ::std::error::Error + 'static) = err;
+            let $bt: &::std::backtrace::Backtrace = &$exception.backtrace;
+            break $label ({ $($body)* })
+        }
+    };
+    ($exception: ident; $name: tt; (); { $($body: tt)* }; $label: lifetime) => {
+        if let Some(err) = $exception.as_dyn_error() {
+            let $name: &(dyn ::std::error::Error + 'static) = err;
+            break $label ({ $($body)* })
+        }
+    };
+}
+
+/// Munches the arm's type-position tokens one at a time, accumulating them into `$ty` until the
+/// unprocessed tail is recognized as one of: nothing left, a trailing `, $bt` binding, a guard
+/// (`if $guard`), or a guard followed by a `, $bt` binding.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __catch_ty_arm {
+    ($exception: ident; $name: tt; [$($ty: tt)+]; (if $guard: expr, $bt: pat); { $($body: tt)* }; $label: lifetime) => {
+        match $exception.source.downcast::<$($ty)+>() {
+            Ok(box_error) => {
+                let matched = { let $name: &$($ty)+ = &*box_error; $guard };
+                if matched {
+                    let $name: $($ty)+ = *box_error;
+                    let $bt: &::std::backtrace::Backtrace = &$exception.backtrace;
+                    break $label ({ $($body)* })
+                }
+                $exception.source = box_error;
+            }
+            Err(other_error) => $exception.source = other_error,
+        }
+    };
+    ($exception: ident; $name: tt; [$($ty: tt)+]; (if $guard: expr); { $($body: tt)* }; $label: lifetime) => {
+        match $exception.source.downcast::<$($ty)+>() {
+            Ok(box_error) => {
+                let matched = { let $name: &$($ty)+ = &*box_error; $guard };
+                if matched {
+                    let $name: $($ty)+ = *box_error;
+                    break $label ({ $($body)* })
+                }
+                $exception.source = box_error;
+            }
+            Err(other_error) => $exception.source = other_error,
+        }
+    };
+    ($exception: ident; $name: tt; [$($ty: tt)+]; (, $bt: pat); { $($body: tt)* }; $label: lifetime) => {
+        match $exception.source.downcast::<$($ty)+>() {
+            Ok(box_error) => {
+                let $name: $($ty)+ = *box_error;
+                let $bt: &::std::backtrace::Backtrace = &$exception.backtrace;
+                break $label ({ $($body)* })
+            }
+            Err(other_error) => $exception.source = other_error,
+        }
+    };
+    ($exception: ident; $name: tt; [$($ty: tt)+]; (); { $($body: tt)* }; $label: lifetime) => {
+        match $exception.source.downcast::<$($ty)+>() {
+            Ok(box_error) => {
+                let $name: $($ty)+ = *box_error;
+                break $label ({ $($body)* })
+            }
+            Err(other_error) => $exception.source = other_error,
+        }
+    };
+    ($exception: ident; $name: tt; [$($ty: tt)*]; ($next: tt $($rest: tt)*); { $($body: tt)* }; $label: lifetime) => {
+        $crate::__catch_ty_arm!($exception; $name; [$($ty)* $next]; ($($rest)*); { $($body)* }; $label)
+    };
 }
\ No newline at end of file